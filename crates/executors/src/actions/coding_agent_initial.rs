@@ -6,12 +6,24 @@ use ts_rs::TS;
 use workspace_utils::vk_mcp_context::VkMcpContext;
 
 use crate::{
-    actions::Executable,
+    actions::{Executable, SpawnPlan},
     approvals::ExecutorApprovalService,
     executors::{BaseCodingAgent, ExecutorError, SpawnedChild, StandardCodingAgentExecutor},
-    profile::{ExecutorConfigs, ExecutorProfileId},
+    profile::ExecutorProfileId,
+    registry::{self, ExecutorIdentity},
 };
 
+/// Resolves `executor_profile_id` through the executor registry, the single resolution
+/// point for both built-in and out-of-tree executors.
+fn resolve_agent(
+    executor_profile_id: &ExecutorProfileId,
+) -> Result<Box<dyn StandardCodingAgentExecutor>, ExecutorError> {
+    let identity = ExecutorIdentity::from(executor_profile_id);
+    registry::get_cached()
+        .resolve(&identity)
+        .map_err(|not_found| ExecutorError::UnknownExecutorType(not_found.to_string()))
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
 pub struct CodingAgentInitialRequest {
     pub prompt: String,
@@ -23,7 +35,10 @@ pub struct CodingAgentInitialRequest {
 
 impl CodingAgentInitialRequest {
     pub fn base_executor(&self) -> BaseCodingAgent {
-        self.executor_profile_id.executor
+        let identity = ExecutorIdentity::from(&self.executor_profile_id);
+        registry::get_cached()
+            .base_agent(&identity)
+            .unwrap_or(self.executor_profile_id.executor)
     }
 }
 
@@ -36,15 +51,44 @@ impl Executable for CodingAgentInitialRequest {
         vk_mcp_context: &VkMcpContext,
     ) -> Result<SpawnedChild, ExecutorError> {
         let executor_profile_id = self.executor_profile_id.clone();
-        let mut agent = ExecutorConfigs::get_cached()
-            .get_coding_agent(&executor_profile_id)
-            .ok_or(ExecutorError::UnknownExecutorType(
-                executor_profile_id.to_string(),
-            ))?;
+        let mut agent = resolve_agent(&executor_profile_id)?;
 
         agent.use_approvals(approvals.clone());
         agent.use_vk_mcp_context(vk_mcp_context);
 
         agent.spawn(current_dir, &self.prompt).await
     }
+
+    async fn plan(
+        &self,
+        current_dir: &Path,
+        vk_mcp_context: &VkMcpContext,
+    ) -> Result<SpawnPlan, ExecutorError> {
+        let executor_profile_id = self.executor_profile_id.clone();
+        let mut agent = resolve_agent(&executor_profile_id)?;
+
+        // `plan` has no live approvals channel to wire up (there's no child process to send
+        // prompts to), so unlike `spawn` it never calls `use_approvals`; `vk_mcp_context` is
+        // plain data, so it's applied here exactly as `spawn` applies it.
+        agent.use_vk_mcp_context(vk_mcp_context);
+
+        // `StandardCodingAgentExecutor::plan` resolves the same command/args/env `spawn`
+        // would launch, without starting a child process; every implementation is expected
+        // to share its resolution logic between the two so `ResolvedSpawn` can't drift from
+        // what `spawn` actually runs.
+        let resolved = agent.plan(current_dir, &self.prompt).await?;
+
+        Ok(SpawnPlan {
+            executor_profile_id,
+            working_dir: current_dir.to_path_buf(),
+            command: resolved.command,
+            args: resolved.args,
+            env: resolved.env,
+            // `spawn` always wires approvals via `use_approvals`, so the plan should reflect
+            // that a real spawn would have them enabled, independent of the fact that `plan`
+            // itself never opens a live channel (see the comment above).
+            approvals_enabled: true,
+            vk_mcp_context_enabled: true,
+        })
+    }
 }
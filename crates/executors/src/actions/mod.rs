@@ -0,0 +1,36 @@
+pub mod coding_agent_initial;
+pub mod spawn_plan;
+
+use std::{path::Path, sync::Arc};
+
+use async_trait::async_trait;
+use workspace_utils::vk_mcp_context::VkMcpContext;
+
+pub use spawn_plan::{ResolvedSpawn, SpawnPlan};
+
+use crate::{
+    approvals::ExecutorApprovalService,
+    executors::{ExecutorError, SpawnedChild},
+};
+
+/// Something that can be turned into a running child process.
+///
+/// Implementors must keep [`plan`](Executable::plan) in lockstep with
+/// [`spawn`](Executable::spawn): `plan` resolves the same executor profile and
+/// MCP context as `spawn` and reports whether approvals would be wired, but
+/// opens no approvals channel and stops short of actually launching anything.
+#[async_trait]
+pub trait Executable {
+    async fn spawn(
+        &self,
+        current_dir: &Path,
+        approvals: Arc<dyn ExecutorApprovalService>,
+        vk_mcp_context: &VkMcpContext,
+    ) -> Result<SpawnedChild, ExecutorError>;
+
+    async fn plan(
+        &self,
+        current_dir: &Path,
+        vk_mcp_context: &VkMcpContext,
+    ) -> Result<SpawnPlan, ExecutorError>;
+}
@@ -0,0 +1,36 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::profile::ExecutorProfileId;
+
+/// A machine-readable description of exactly what [`Executable::spawn`] would
+/// run, without actually launching a child process.
+///
+/// Mirrors `cargo build --build-plan`: callers can preview or diff the
+/// resolved command line, environment, and working directory for a given
+/// prompt + [`ExecutorProfileId`] before committing to an execution.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
+pub struct SpawnPlan {
+    pub executor_profile_id: ExecutorProfileId,
+    pub working_dir: PathBuf,
+    pub command: String,
+    pub args: Vec<String>,
+    /// Full environment that would be injected into the child process,
+    /// including `VK_MCP_CONTEXT_JSON`.
+    pub env: BTreeMap<String, String>,
+    pub approvals_enabled: bool,
+    pub vk_mcp_context_enabled: bool,
+}
+
+/// The command/args/env a `StandardCodingAgentExecutor` resolves for a prompt. `plan`
+/// returns this directly; `spawn` is expected to reuse the same resolution internally
+/// before launching, so the two don't drift — but that's a contract each executor
+/// implementation upholds on its own, not something enforced by this struct.
+#[derive(Debug, Clone)]
+pub struct ResolvedSpawn {
+    pub command: String,
+    pub args: Vec<String>,
+    pub env: BTreeMap<String, String>,
+}
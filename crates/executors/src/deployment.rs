@@ -0,0 +1,34 @@
+use std::{path::Path, sync::Arc};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+use workspace_utils::vk_mcp_context::VkMcpContext;
+
+use crate::{
+    actions::coding_agent_initial::CodingAgentInitialRequest,
+    approvals::ExecutorApprovalService,
+    executors::{ExecutorError, SpawnedChild},
+};
+
+/// How an execution actually runs: as a local child process, as a Kubernetes pod, or
+/// whatever a future deployment target adds. `server::DeploymentImpl` aliases to
+/// whichever implementation is compiled in, so every implementation must satisfy this
+/// same contract to be a drop-in replacement.
+#[async_trait]
+pub trait Deployment: Send + Sync {
+    async fn spawn(
+        &self,
+        request: &CodingAgentInitialRequest,
+        current_dir: &Path,
+        vk_mcp_context: &VkMcpContext,
+    ) -> Result<SpawnedChild, ExecutorError>;
+
+    /// The approval channel executions on this deployment should route prompts through,
+    /// so approval/MCP traffic gets back to the orchestrator regardless of where the
+    /// agent itself is actually running.
+    fn approval_service(&self, execution_process_id: Uuid) -> Arc<dyn ExecutorApprovalService>;
+
+    /// Tears down whatever backs `execution_process_id` (a pod, a child process, ...),
+    /// whether it already finished or is being cancelled early.
+    async fn cancel(&self, execution_process_id: Uuid);
+}
@@ -0,0 +1,231 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Once, RwLock},
+};
+
+use once_cell::sync::Lazy;
+use strsim::jaro_winkler;
+
+use crate::{
+    executors::{BaseCodingAgent, StandardCodingAgentExecutor},
+    profile::{ExecutorConfigs, ExecutorProfileId},
+};
+
+/// The executor profile schema version all built-in (`ExecutorConfigs`-backed) profiles
+/// currently register under.
+const BUILTIN_SCHEMA_VERSION: u32 = 1;
+
+impl From<&ExecutorProfileId> for ExecutorIdentity {
+    fn from(executor_profile_id: &ExecutorProfileId) -> Self {
+        ExecutorIdentity::new(
+            executor_profile_id.executor.to_string(),
+            executor_profile_id.to_string(),
+            BUILTIN_SCHEMA_VERSION,
+        )
+    }
+}
+
+/// The full identity of a registered executor: its name, variant (e.g. a specific
+/// sub-profile of an agent), and the schema version of the config it expects.
+/// Third-party/out-of-tree executors register under their own identity rather than
+/// editing a central enum.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExecutorIdentity {
+    pub name: String,
+    pub variant: String,
+    pub schema_version: u32,
+}
+
+impl fmt::Display for ExecutorIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}@v{}", self.name, self.variant, self.schema_version)
+    }
+}
+
+impl ExecutorIdentity {
+    pub fn new(name: impl Into<String>, variant: impl Into<String>, schema_version: u32) -> Self {
+        Self {
+            name: name.into(),
+            variant: variant.into(),
+            schema_version,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "no executor registered for `{requested}`; closest registered alternatives: {}",
+    closest.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+)]
+pub struct ExecutorNotFound {
+    pub requested: ExecutorIdentity,
+    pub closest: Vec<ExecutorIdentity>,
+}
+
+/// A coding agent that can be resolved purely by its [`ExecutorIdentity`], independent of
+/// the built-in [`BaseCodingAgent`] enum. Registered executors must still be able to
+/// produce a [`StandardCodingAgentExecutor`] so existing spawn/plan plumbing keeps working.
+pub trait RegisteredExecutor: Send + Sync {
+    fn identity(&self) -> &ExecutorIdentity;
+    fn base_agent(&self) -> BaseCodingAgent;
+    fn build(&self) -> Box<dyn StandardCodingAgentExecutor>;
+}
+
+/// Wraps an `ExecutorConfigs`-backed profile so it can register under an [`ExecutorIdentity`]
+/// alongside out-of-tree executors, without `ExecutorConfigs` itself needing to know about
+/// the registry.
+struct ConfigBackedExecutor {
+    identity: ExecutorIdentity,
+    profile_id: ExecutorProfileId,
+}
+
+impl RegisteredExecutor for ConfigBackedExecutor {
+    fn identity(&self) -> &ExecutorIdentity {
+        &self.identity
+    }
+
+    fn base_agent(&self) -> BaseCodingAgent {
+        self.profile_id.executor
+    }
+
+    fn build(&self) -> Box<dyn StandardCodingAgentExecutor> {
+        ExecutorConfigs::get_cached()
+            .get_coding_agent(&self.profile_id)
+            .expect("profile registered from ExecutorConfigs must still resolve there")
+    }
+}
+
+/// The single resolution point for turning an [`ExecutorIdentity`] into a runnable
+/// executor. Built-in executors are registered via [`ExecutorRegistry::register`] on the
+/// first [`get_cached`] call; out-of-tree executors can register the same way.
+#[derive(Default)]
+pub struct ExecutorRegistry {
+    executors: RwLock<HashMap<ExecutorIdentity, Box<dyn RegisteredExecutor>>>,
+    builtins_registered: Once,
+}
+
+impl ExecutorRegistry {
+    pub fn register(&self, executor: Box<dyn RegisteredExecutor>) {
+        let identity = executor.identity().clone();
+        self.executors.write().unwrap().insert(identity, executor);
+    }
+
+    /// Registers every profile `ExecutorConfigs` currently knows about, so the registry is
+    /// never empty by the time a lookup happens. Idempotent; safe to call from every
+    /// `get_cached()`. Relies on `ExecutorConfigs::profile_ids()` enumerating every
+    /// built-in profile; `ExecutorConfigs` lives outside this crate.
+    fn ensure_builtins_registered(&self) {
+        self.builtins_registered.call_once(|| {
+            for profile_id in ExecutorConfigs::get_cached().profile_ids() {
+                self.register(Box::new(ConfigBackedExecutor {
+                    identity: ExecutorIdentity::from(&profile_id),
+                    profile_id,
+                }));
+            }
+        });
+    }
+
+    /// The `BaseCodingAgent` a registered identity maps to, without fully building the
+    /// executor. Used by callers (like `base_executor()`) that just need to know which
+    /// agent a profile resolves to.
+    pub fn base_agent(&self, identity: &ExecutorIdentity) -> Option<BaseCodingAgent> {
+        self.executors
+            .read()
+            .unwrap()
+            .get(identity)
+            .map(|executor| executor.base_agent())
+    }
+
+    pub fn resolve(
+        &self,
+        identity: &ExecutorIdentity,
+    ) -> Result<Box<dyn StandardCodingAgentExecutor>, ExecutorNotFound> {
+        if let Some(executor) = self.executors.read().unwrap().get(identity) {
+            return Ok(executor.build());
+        }
+
+        Err(ExecutorNotFound {
+            requested: identity.clone(),
+            closest: self.suggest(&identity.to_string()),
+        })
+    }
+
+    /// Registered identities ranked by string similarity to `requested`, for surfacing
+    /// actionable suggestions in not-found errors. Also what [`resolve`](Self::resolve)
+    /// itself calls to fill in [`ExecutorNotFound::closest`].
+    pub fn suggest(&self, requested: &str) -> Vec<ExecutorIdentity> {
+        let identities: Vec<_> = self.executors.read().unwrap().keys().cloned().collect();
+        closest_to(requested, &identities)
+    }
+}
+
+/// Ranks `identities` by string similarity to `requested`, nearest first, keeping only
+/// the top 3. Pulled out of [`ExecutorRegistry`] so it can be unit-tested without needing
+/// a live [`StandardCodingAgentExecutor`] behind every candidate.
+fn closest_to(requested: &str, identities: &[ExecutorIdentity]) -> Vec<ExecutorIdentity> {
+    let mut closest: Vec<_> = identities.to_vec();
+    closest.sort_by(|a, b| {
+        jaro_winkler(&b.to_string(), requested)
+            .partial_cmp(&jaro_winkler(&a.to_string(), requested))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    closest.truncate(3);
+    closest
+}
+
+static REGISTRY: Lazy<ExecutorRegistry> = Lazy::new(ExecutorRegistry::default);
+
+pub fn get_cached() -> &'static ExecutorRegistry {
+    REGISTRY.ensure_builtins_registered();
+    &REGISTRY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity(name: &str) -> ExecutorIdentity {
+        ExecutorIdentity::new(name, "default", 1)
+    }
+
+    #[test]
+    fn closest_to_ranks_nearest_matches_first() {
+        let identities = [identity("claude-code"), identity("codex"), identity("aider")];
+
+        let ranked = closest_to("claude-cod", &identities);
+
+        assert_eq!(ranked[0], identity("claude-code"));
+    }
+
+    #[test]
+    fn closest_to_truncates_to_three() {
+        let identities = [
+            identity("claude-code"),
+            identity("codex"),
+            identity("aider"),
+            identity("cursor"),
+            identity("windsurf"),
+        ];
+
+        assert_eq!(closest_to("claude-code", &identities).len(), 3);
+    }
+
+    #[test]
+    fn closest_to_empty_registry_suggests_nothing() {
+        assert!(closest_to("anything", &[]).is_empty());
+    }
+
+    #[test]
+    fn executor_not_found_message_lists_suggestions() {
+        let err = ExecutorNotFound {
+            requested: identity("calude-code"),
+            closest: vec![identity("claude-code"), identity("codex")],
+        };
+
+        let message = err.to_string();
+        assert!(message.contains("calude-code/default@v1"));
+        assert!(message.contains("claude-code/default@v1"));
+        assert!(message.contains("codex/default@v1"));
+    }
+}
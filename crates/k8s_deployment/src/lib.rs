@@ -0,0 +1,151 @@
+pub mod pod_spec;
+
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use executors::{
+    actions::coding_agent_initial::CodingAgentInitialRequest,
+    approvals::ExecutorApprovalService,
+    deployment::Deployment,
+    executors::{ExecutorError, SpawnedChild, StandardCodingAgentExecutor},
+    profile::ExecutorProfileId,
+    registry::{self, ExecutorIdentity},
+};
+use futures::TryStreamExt;
+use k8s_openapi::api::core::v1::Pod;
+use kube::{
+    Api, Client,
+    api::{DeleteParams, LogParams, PostParams},
+};
+use uuid::Uuid;
+use workspace_utils::vk_mcp_context::VkMcpContext;
+
+pub use pod_spec::{PodSpawnError, build_pod_spec};
+
+type ApprovalServiceFactory = dyn Fn(Uuid) -> Arc<dyn ExecutorApprovalService> + Send + Sync;
+
+/// Runs each coding agent execution as a short-lived pod on a Kubernetes cluster, rather
+/// than as a local child process. Implements the same [`Deployment`] contract as
+/// `local_deployment::LocalDeployment` so `server::DeploymentImpl` can alias to either
+/// without any caller-side changes.
+pub struct KubernetesDeployment {
+    client: Client,
+    namespace: String,
+    approval_service_factory: Arc<ApprovalServiceFactory>,
+    live_pods: RwLock<HashMap<Uuid, String>>,
+}
+
+impl KubernetesDeployment {
+    /// `approval_service_factory` lets the orchestrator supply its own approval routing
+    /// (e.g. the tunnel subsystem's relay) without this crate needing to know about it.
+    pub async fn try_new(
+        namespace: impl Into<String>,
+        approval_service_factory: Arc<ApprovalServiceFactory>,
+    ) -> Result<Self, ExecutorError> {
+        let client = Client::try_default()
+            .await
+            .map_err(|e| ExecutorError::Io(std::io::Error::other(e)))?;
+
+        Ok(Self {
+            client,
+            namespace: namespace.into(),
+            approval_service_factory,
+            live_pods: RwLock::new(HashMap::new()),
+        })
+    }
+
+    fn pods(&self) -> Api<Pod> {
+        Api::namespaced(self.client.clone(), &self.namespace)
+    }
+}
+
+/// Resolves `executor_profile_id` through the executor registry, the same single
+/// resolution point `CodingAgentInitialRequest::spawn`/`plan` use, so a pod and a local
+/// child process never disagree about which executor a profile maps to.
+fn resolve_agent(
+    executor_profile_id: &ExecutorProfileId,
+) -> Result<Box<dyn StandardCodingAgentExecutor>, ExecutorError> {
+    let identity = ExecutorIdentity::from(executor_profile_id);
+    registry::get_cached()
+        .resolve(&identity)
+        .map_err(|not_found| ExecutorError::UnknownExecutorType(not_found.to_string()))
+}
+
+#[async_trait]
+impl Deployment for KubernetesDeployment {
+    /// Resolves the request's executor through the registry, creates a pod carrying
+    /// `vk_mcp_context` as env vars (including `VK_MCP_CONTEXT_JSON`), and streams its
+    /// logs back as a `SpawnedChild`.
+    async fn spawn(
+        &self,
+        request: &CodingAgentInitialRequest,
+        _current_dir: &Path,
+        vk_mcp_context: &VkMcpContext,
+    ) -> Result<SpawnedChild, ExecutorError> {
+        let executor_profile_id = request.executor_profile_id.clone();
+        // `container_image` is expected alongside `StandardCodingAgentExecutor`'s other
+        // methods (its trait is defined outside this crate); every executor usable from a
+        // pod needs to expose the image it should run in.
+        let agent = resolve_agent(&executor_profile_id)?;
+
+        let pod = build_pod_spec(
+            &executor_profile_id,
+            agent.container_image(),
+            &request.prompt,
+            vk_mcp_context,
+        )
+        .map_err(|e| ExecutorError::Io(std::io::Error::other(e)))?;
+
+        let pods = self.pods();
+        let created = pods
+            .create(&PostParams::default(), &pod)
+            .await
+            .map_err(|e| ExecutorError::Io(std::io::Error::other(e)))?;
+
+        let pod_name = created
+            .metadata
+            .name
+            .clone()
+            .ok_or_else(|| ExecutorError::Io(std::io::Error::other("pod created without a name")))?;
+
+        self.live_pods
+            .write()
+            .unwrap()
+            .insert(vk_mcp_context.execution_process_id, pod_name.clone());
+
+        let log_stream = pods
+            .log_stream(
+                &pod_name,
+                &LogParams {
+                    follow: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|e| ExecutorError::Io(std::io::Error::other(e)))?;
+
+        // Container logs interleave stdout/stderr into a single stream, so unlike a local
+        // child process this `SpawnedChild` has no distinct stderr of its own.
+        // `from_async_reader` is expected alongside `SpawnedChild`'s process-backed
+        // constructor (its type is defined outside this crate) for exactly this case:
+        // wrapping a log stream that never had a separate stdout/stderr pair.
+        Ok(SpawnedChild::from_async_reader(
+            log_stream.into_async_read(),
+        ))
+    }
+
+    fn approval_service(&self, execution_process_id: Uuid) -> Arc<dyn ExecutorApprovalService> {
+        (self.approval_service_factory)(execution_process_id)
+    }
+
+    async fn cancel(&self, execution_process_id: Uuid) {
+        let pod_name = self.live_pods.write().unwrap().remove(&execution_process_id);
+        if let Some(pod_name) = pod_name {
+            let _ = self.pods().delete(&pod_name, &DeleteParams::default()).await;
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use k8s_openapi::api::core::v1::{Container, EnvVar, Pod, PodSpec};
+use kube::api::ObjectMeta;
+use uuid::Uuid;
+use workspace_utils::vk_mcp_context::{VK_MCP_CONTEXT_ENV, VkMcpContext};
+
+use executors::profile::ExecutorProfileId;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PodSpawnError {
+    #[error("failed to serialize vk mcp context: {0}")]
+    Serialize(#[from] serde_json::Error),
+}
+
+/// Builds the pod spec for a single coding agent execution: one container running
+/// `image`, with every field of `VkMcpContext` mapped onto an env var (carrying
+/// `VK_MCP_CONTEXT_JSON` just as the local deployment path does).
+pub fn build_pod_spec(
+    executor_profile_id: &ExecutorProfileId,
+    image: &str,
+    prompt: &str,
+    vk_mcp_context: &VkMcpContext,
+) -> Result<Pod, PodSpawnError> {
+    let pod_name = format!("vk-exec-{}", Uuid::new_v4());
+    let env = build_env(&executor_profile_id.to_string(), prompt, vk_mcp_context)?;
+
+    Ok(Pod {
+        metadata: ObjectMeta {
+            name: Some(pod_name),
+            labels: Some(
+                [("app".to_string(), "vk-execution".to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            ..Default::default()
+        },
+        spec: Some(PodSpec {
+            restart_policy: Some("Never".to_string()),
+            containers: vec![Container {
+                name: "agent".to_string(),
+                image: Some(image.to_string()),
+                env: Some(env),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+        status: None,
+    })
+}
+
+fn env_var(name: &str, value: &str) -> EnvVar {
+    EnvVar {
+        name: name.to_string(),
+        value: Some(value.to_string()),
+        value_from: None,
+    }
+}
+
+/// The env vars every pod carries, given an already-formatted `executor_profile_id`
+/// (rather than the type itself) so this mapping can be unit-tested without needing a
+/// constructible `ExecutorProfileId`, which is defined outside this crate.
+fn build_env(
+    executor_profile_id: &str,
+    prompt: &str,
+    vk_mcp_context: &VkMcpContext,
+) -> Result<Vec<EnvVar>, PodSpawnError> {
+    Ok(vec![
+        env_var("VK_PROMPT", prompt),
+        env_var(VK_MCP_CONTEXT_ENV, &serde_json::to_string(vk_mcp_context)?),
+        env_var("VK_PROJECT_ID", &vk_mcp_context.project_id.to_string()),
+        env_var("VK_TASK_ID", &vk_mcp_context.task_id.to_string()),
+        env_var("VK_TASK_TITLE", &vk_mcp_context.task_title),
+        env_var("VK_ATTEMPT_ID", &vk_mcp_context.attempt_id.to_string()),
+        env_var("VK_ATTEMPT_BRANCH", &vk_mcp_context.attempt_branch),
+        env_var(
+            "VK_ATTEMPT_TARGET_BRANCH",
+            &vk_mcp_context.attempt_target_branch,
+        ),
+        env_var(
+            "VK_EXECUTION_PROCESS_ID",
+            &vk_mcp_context.execution_process_id.to_string(),
+        ),
+        env_var("VK_EXECUTOR", &vk_mcp_context.executor),
+        env_var("VK_EXECUTOR_PROFILE_ID", executor_profile_id),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn sample_context() -> VkMcpContext {
+        VkMcpContext {
+            project_id: Uuid::new_v4(),
+            task_id: Uuid::new_v4(),
+            task_title: "Fix the thing".to_string(),
+            attempt_id: Uuid::new_v4(),
+            attempt_branch: "vk/fix-the-thing".to_string(),
+            attempt_target_branch: "main".to_string(),
+            execution_process_id: Uuid::new_v4(),
+            executor: "claude-code".to_string(),
+        }
+    }
+
+    fn find<'a>(env: &'a [EnvVar], name: &str) -> &'a EnvVar {
+        env.iter()
+            .find(|var| var.name == name)
+            .unwrap_or_else(|| panic!("missing env var {name}"))
+    }
+
+    #[test]
+    fn maps_vk_mcp_context_fields_onto_env_vars() {
+        let context = sample_context();
+        let env = build_env("claude-code/default@v1", "do the thing", &context).unwrap();
+
+        assert_eq!(find(&env, "VK_PROMPT").value.as_deref(), Some("do the thing"));
+        assert_eq!(
+            find(&env, "VK_PROJECT_ID").value.as_deref(),
+            Some(context.project_id.to_string().as_str())
+        );
+        assert_eq!(
+            find(&env, "VK_TASK_TITLE").value.as_deref(),
+            Some("Fix the thing")
+        );
+        assert_eq!(
+            find(&env, "VK_ATTEMPT_BRANCH").value.as_deref(),
+            Some("vk/fix-the-thing")
+        );
+        assert_eq!(
+            find(&env, "VK_EXECUTION_PROCESS_ID").value.as_deref(),
+            Some(context.execution_process_id.to_string().as_str())
+        );
+        assert_eq!(find(&env, "VK_EXECUTOR").value.as_deref(), Some("claude-code"));
+        assert_eq!(
+            find(&env, "VK_EXECUTOR_PROFILE_ID").value.as_deref(),
+            Some("claude-code/default@v1")
+        );
+    }
+
+    #[test]
+    fn vk_mcp_context_json_round_trips_the_whole_struct() {
+        let context = sample_context();
+        let env = build_env("claude-code/default@v1", "prompt", &context).unwrap();
+
+        let raw = find(&env, VK_MCP_CONTEXT_ENV).value.clone().unwrap();
+        let decoded: VkMcpContext = serde_json::from_str(&raw).unwrap();
+        assert_eq!(decoded, context);
+    }
+}
@@ -0,0 +1,30 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use executors::executors::ExecutorError;
+use serde_json::json;
+
+use crate::tunnel::TunnelError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApiError {
+    #[error(transparent)]
+    Executor(#[from] ExecutorError),
+    #[error(transparent)]
+    Tunnel(#[from] TunnelError),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            ApiError::Executor(ExecutorError::UnknownExecutorType(_)) => StatusCode::BAD_REQUEST,
+            ApiError::Executor(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            ApiError::Tunnel(TunnelError::NotFound(_)) => StatusCode::NOT_FOUND,
+            ApiError::Tunnel(TunnelError::LeaseHeld(_)) => StatusCode::CONFLICT,
+        };
+
+        (status, Json(json!({ "error": self.to_string() }))).into_response()
+    }
+}
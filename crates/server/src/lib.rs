@@ -2,8 +2,13 @@ pub mod error;
 pub mod mcp;
 pub mod middleware;
 pub mod routes;
+pub mod tls;
+pub mod tunnel;
 
-// #[cfg(feature = "cloud")]
-// type DeploymentImpl = anyon_cloud::deployment::CloudDeployment;
-// #[cfg(not(feature = "cloud"))]
+#[cfg(feature = "kubernetes")]
+pub type DeploymentImpl = k8s_deployment::KubernetesDeployment;
+// `local_deployment` is an out-of-tree crate (not vendored alongside this one); it must
+// implement `executors::deployment::Deployment` the same way `k8s_deployment::KubernetesDeployment`
+// does, so every route and handler above can stay agnostic to which deployment backs it.
+#[cfg(not(feature = "kubernetes"))]
 pub type DeploymentImpl = local_deployment::LocalDeployment;
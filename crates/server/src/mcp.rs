@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use axum::{Json, Router, extract::State, http::StatusCode, routing::post};
+use executors::approvals::ApprovalRequest;
+use serde::Deserialize;
+use tokio_rustls::TlsAcceptor;
+use uuid::Uuid;
+
+use crate::{error::ApiError, middleware, routes::AppState, tls::TlsConfig, tunnel::TunnelRegistry};
+
+/// Serves the MCP endpoint over TLS, rejecting any call that doesn't present a valid
+/// bearer token before it reaches handlers that read/write `VkMcpContext`.
+pub struct McpServer {
+    tls_acceptor: TlsAcceptor,
+    bearer_token: String,
+}
+
+impl McpServer {
+    pub fn new(tls: &TlsConfig, bearer_token: impl Into<String>) -> Result<Self, crate::tls::TlsError> {
+        let server_config = tls.build_server_config()?;
+
+        Ok(Self {
+            tls_acceptor: TlsAcceptor::from(Arc::new(server_config)),
+            bearer_token: bearer_token.into(),
+        })
+    }
+
+    pub fn tls_acceptor(&self) -> TlsAcceptor {
+        self.tls_acceptor.clone()
+    }
+
+    /// MCP router, gated by [`middleware::require_bearer_token`] before any call reaches
+    /// `VkMcpContext`-bearing tool handlers.
+    pub fn router(&self) -> Router<AppState> {
+        let token = self.bearer_token.clone();
+
+        Router::new()
+            .route("/approvals", post(submit_approval_request))
+            .route_layer(axum::middleware::from_fn(move |headers, req, next| {
+                let token = token.clone();
+                async move { middleware::require_bearer_token(token, headers, req, next).await }
+            }))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitApprovalRequest {
+    pub execution_process_id: Uuid,
+    pub request: ApprovalRequest,
+}
+
+/// Called back by the executing agent (over MCP) to raise an approval prompt, feeding it
+/// into the execution's tunnel so whoever holds the approval lease can drain it via
+/// `GET /attach/{execution_process_id}/approvals/next`.
+pub async fn submit_approval_request(
+    State(tunnels): State<Arc<TunnelRegistry>>,
+    Json(body): Json<SubmitApprovalRequest>,
+) -> Result<StatusCode, ApiError> {
+    tunnels.submit_approval_request(body.execution_process_id, body.request)?;
+
+    Ok(StatusCode::OK)
+}
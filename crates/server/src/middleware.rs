@@ -0,0 +1,174 @@
+use axum::{
+    extract::Request,
+    http::{HeaderMap, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+
+/// Header carrying the bearer token remote tunnel clients must present before an
+/// attach/approval request reaches `VkMcpContext`-bearing routes.
+pub const ATTACH_TOKEN_HEADER: &str = "x-vk-attach-token";
+
+/// Rejects any request that doesn't present `expected_token` via [`ATTACH_TOKEN_HEADER`].
+/// Wraps the tunnel attach routes so an unauthenticated caller never reaches
+/// `TunnelRegistry::watch`/`acquire_approval_lease`.
+pub async fn require_attach_token(
+    expected_token: String,
+    headers: HeaderMap,
+    request: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let presented = headers
+        .get(ATTACH_TOKEN_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    match presented {
+        Some(token) if constant_time_eq(token.as_bytes(), expected_token.as_bytes()) => {
+            Ok(next.run(request).await)
+        }
+        _ => Err(StatusCode::UNAUTHORIZED),
+    }
+}
+
+/// Rejects any request that doesn't present `expected_token` as an `Authorization: Bearer`
+/// header. Applied in front of every `routes::router` and `mcp` call so an unauthenticated
+/// caller never reaches code that touches `VkMcpContext`; the attach routes are gated by
+/// [`require_attach_token`] instead.
+pub async fn require_bearer_token(
+    expected_token: String,
+    headers: HeaderMap,
+    request: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let presented = headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match presented {
+        Some(token) if constant_time_eq(token.as_bytes(), expected_token.as_bytes()) => {
+            Ok(next.run(request).await)
+        }
+        _ => Err(StatusCode::UNAUTHORIZED),
+    }
+}
+
+/// Compares two byte slices in time proportional to their length, not their contents,
+/// so a wrong guess can't be timed to learn the token one byte at a time.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, body::Body, http::Request as HttpRequest, routing::get};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    #[test]
+    fn constant_time_eq_accepts_equal_slices() {
+        assert!(constant_time_eq(b"same-token", b"same-token"));
+    }
+
+    #[test]
+    fn constant_time_eq_rejects_different_length() {
+        assert!(!constant_time_eq(b"short", b"much-longer-value"));
+    }
+
+    #[test]
+    fn constant_time_eq_rejects_different_content_same_length() {
+        assert!(!constant_time_eq(b"token-aaaa", b"token-bbbb"));
+    }
+
+    fn guarded_router(token: &str) -> Router {
+        let token = token.to_string();
+        Router::new()
+            .route("/", get(|| async { "ok" }))
+            .route_layer(axum::middleware::from_fn(move |headers, req, next| {
+                let token = token.clone();
+                async move { require_bearer_token(token, headers, req, next).await }
+            }))
+    }
+
+    #[tokio::test]
+    async fn bearer_token_rejects_missing_header() {
+        let response = guarded_router("secret")
+            .oneshot(HttpRequest::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn bearer_token_rejects_wrong_token() {
+        let response = guarded_router("secret")
+            .oneshot(
+                HttpRequest::builder()
+                    .uri("/")
+                    .header(axum::http::header::AUTHORIZATION, "Bearer wrong")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn bearer_token_accepts_matching_token() {
+        let response = guarded_router("secret")
+            .oneshot(
+                HttpRequest::builder()
+                    .uri("/")
+                    .header(axum::http::header::AUTHORIZATION, "Bearer secret")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    fn attach_guarded_router(token: &str) -> Router {
+        let token = token.to_string();
+        Router::new()
+            .route("/", get(|| async { "ok" }))
+            .route_layer(axum::middleware::from_fn(move |headers, req, next| {
+                let token = token.clone();
+                async move { require_attach_token(token, headers, req, next).await }
+            }))
+    }
+
+    #[tokio::test]
+    async fn attach_token_rejects_missing_header() {
+        let response = attach_guarded_router("secret")
+            .oneshot(HttpRequest::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn attach_token_accepts_matching_token() {
+        let response = attach_guarded_router("secret")
+            .oneshot(
+                HttpRequest::builder()
+                    .uri("/")
+                    .header(ATTACH_TOKEN_HEADER, "secret")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,219 @@
+use std::{path::PathBuf, sync::Arc};
+
+use axum::{
+    Json,
+    extract::{
+        FromRef, Path, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{get, post},
+};
+use executors::{
+    actions::{Executable, SpawnPlan, coding_agent_initial::CodingAgentInitialRequest},
+    approvals::ApprovalResponse,
+    deployment::Deployment,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+use workspace_utils::vk_mcp_context::VkMcpContext;
+
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    middleware,
+    tunnel::{OutputLine, TunnelRegistry, TunnelViewer},
+};
+
+/// Shared state for every route in [`router`]: the deployment that actually runs
+/// executions, and the tunnel registry that lets a remote client attach to one.
+#[derive(Clone)]
+pub struct AppState {
+    pub deployment: Arc<DeploymentImpl>,
+    pub tunnels: Arc<TunnelRegistry>,
+}
+
+impl FromRef<AppState> for Arc<DeploymentImpl> {
+    fn from_ref(state: &AppState) -> Self {
+        state.deployment.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<TunnelRegistry> {
+    fn from_ref(state: &AppState) -> Self {
+        state.tunnels.clone()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlanSpawnRequest {
+    pub request: CodingAgentInitialRequest,
+    pub current_dir: PathBuf,
+    pub vk_mcp_context: VkMcpContext,
+}
+
+/// Resolve a [`CodingAgentInitialRequest`] into a [`SpawnPlan`] without spawning anything,
+/// so a caller (or CI) can preview/diff exactly what a prompt + profile would run.
+pub async fn plan_spawn(
+    State(_deployment): State<Arc<DeploymentImpl>>,
+    Json(body): Json<PlanSpawnRequest>,
+) -> Result<Json<SpawnPlan>, ApiError> {
+    let plan = body
+        .request
+        .plan(&body.current_dir, &body.vk_mcp_context)
+        .await?;
+
+    Ok(Json(plan))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpawnExecutionRequest {
+    pub request: CodingAgentInitialRequest,
+    pub current_dir: PathBuf,
+    pub vk_mcp_context: VkMcpContext,
+}
+
+/// Actually spawns the execution, registering it with the [`TunnelRegistry`] under its
+/// `execution_process_id` before it starts so a remote client can `attach` to it as soon
+/// as it's running, and relaying its stdout/stderr into the tunnel until it exits.
+pub async fn spawn_execution(
+    State(deployment): State<Arc<DeploymentImpl>>,
+    State(tunnels): State<Arc<TunnelRegistry>>,
+    Json(body): Json<SpawnExecutionRequest>,
+) -> Result<StatusCode, ApiError> {
+    let execution_process_id = body.vk_mcp_context.execution_process_id;
+
+    let output_tx = tunnels.open(execution_process_id);
+
+    let mut child = deployment
+        .spawn(&body.request, &body.current_dir, &body.vk_mcp_context)
+        .await?;
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                Some(line) = child.next_stdout_line() => {
+                    let _ = output_tx.send(OutputLine::Stdout(line));
+                }
+                Some(line) = child.next_stderr_line() => {
+                    let _ = output_tx.send(OutputLine::Stderr(line));
+                }
+                else => break,
+            }
+        }
+        deployment.cancel(execution_process_id).await;
+        tunnels.close(execution_process_id);
+    });
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// Pulls the next pending approval prompt for `execution_process_id`, acquiring the
+/// approval lease for the duration of this call. Waits until a prompt arrives;
+/// `204 No Content` means the execution has finished and no further prompts will come.
+pub async fn next_approval_request(
+    Path(execution_process_id): Path<Uuid>,
+    State(deployment): State<Arc<DeploymentImpl>>,
+    State(tunnels): State<Arc<TunnelRegistry>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let lease = tunnels.acquire_approval_lease(
+        execution_process_id,
+        deployment.approval_service(execution_process_id),
+    )?;
+
+    Ok(match lease.next_request().await {
+        Some(request) => (StatusCode::OK, Json(request)).into_response(),
+        None => StatusCode::NO_CONTENT.into_response(),
+    })
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RespondToApprovalRequest {
+    pub request_id: Uuid,
+    pub response: ApprovalResponse,
+}
+
+/// Answers a pending approval prompt for `execution_process_id`, acquiring the approval
+/// lease for the duration of this call.
+pub async fn respond_to_approval_request(
+    Path(execution_process_id): Path<Uuid>,
+    State(deployment): State<Arc<DeploymentImpl>>,
+    State(tunnels): State<Arc<TunnelRegistry>>,
+    Json(body): Json<RespondToApprovalRequest>,
+) -> Result<StatusCode, ApiError> {
+    let lease = tunnels.acquire_approval_lease(
+        execution_process_id,
+        deployment.approval_service(execution_process_id),
+    )?;
+
+    lease.respond(body.request_id, body.response).await?;
+
+    Ok(StatusCode::OK)
+}
+
+/// `bearer_token` gates every route here via [`middleware::require_bearer_token`] before a
+/// caller reaches handlers that touch `VkMcpContext`.
+pub fn router(bearer_token: String) -> axum::Router<AppState> {
+    axum::Router::new()
+        .route("/executions/plan", post(plan_spawn))
+        .route("/executions/spawn", post(spawn_execution))
+        .route_layer(axum::middleware::from_fn(move |headers, req, next| {
+            let bearer_token = bearer_token.clone();
+            async move { middleware::require_bearer_token(bearer_token, headers, req, next).await }
+        }))
+}
+
+/// Upgrades to a websocket streaming an in-flight execution's stdout/stderr read-only.
+/// Any number of viewers may attach to the same `execution_process_id` concurrently.
+pub async fn attach(
+    Path(execution_process_id): Path<Uuid>,
+    State(tunnels): State<Arc<TunnelRegistry>>,
+    ws: WebSocketUpgrade,
+) -> Result<impl IntoResponse, ApiError> {
+    let viewer = tunnels.watch(execution_process_id)?;
+
+    Ok(ws.on_upgrade(move |socket| stream_output(socket, viewer)))
+}
+
+async fn stream_output(mut socket: WebSocket, mut viewer: TunnelViewer) {
+    while let Ok(line) = viewer.output.recv().await {
+        let text = match line {
+            OutputLine::Stdout(s) | OutputLine::Stderr(s) => s,
+        };
+        if socket.send(Message::Text(text.into())).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Router for the remote attach/tunnel endpoints, gated by `require_attach_token` so a
+/// client on another host must present `attach_token` before reaching a live
+/// `execution_process_id`. Carries `deployment` alongside `tunnels` so the lease-holder
+/// routes can resolve each execution's `ExecutorApprovalService`.
+pub fn attach_router(
+    deployment: Arc<DeploymentImpl>,
+    tunnels: Arc<TunnelRegistry>,
+    attach_token: Arc<String>,
+) -> axum::Router {
+    axum::Router::new()
+        .route("/attach/{execution_process_id}", get(attach))
+        .route(
+            "/attach/{execution_process_id}/approvals/next",
+            get(next_approval_request),
+        )
+        .route(
+            "/attach/{execution_process_id}/approvals/respond",
+            post(respond_to_approval_request),
+        )
+        .route_layer(axum::middleware::from_fn_with_state(
+            attach_token,
+            |State(token): State<Arc<String>>, headers, req, next| async move {
+                middleware::require_attach_token((*token).clone(), headers, req, next).await
+            },
+        ))
+        .with_state(AppState {
+            deployment,
+            tunnels,
+        })
+}
@@ -0,0 +1,114 @@
+use std::{
+    fs,
+    io::Cursor,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rustls::{
+    RootCertStore, ServerConfig,
+    pki_types::{CertificateDer, PrivateKeyDer},
+    server::WebPkiClientVerifier,
+};
+use rustls_pemfile::{certs, private_key};
+
+#[derive(Debug, thiserror::Error)]
+pub enum TlsError {
+    #[error("failed to read {path}: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to write {path}: {source}")]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("no private key found in {0}")]
+    NoPrivateKey(PathBuf),
+    #[error("failed to generate self-signed dev cert: {0}")]
+    GenerateCert(#[from] rcgen::Error),
+    #[error(transparent)]
+    Rustls(#[from] rustls::Error),
+    #[error(transparent)]
+    Verifier(#[from] rustls::server::VerifierBuilderError),
+}
+
+/// Where the orchestrator's TLS material lives, and whether it should demand a client
+/// certificate. Self-signed dev certs (see [`generate_self_signed_dev_cert`]) satisfy this
+/// same shape; production deployments point `cert_path`/`key_path` at real certs.
+#[derive(Debug, Clone)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+    /// CA bundle used to verify client certificates. `None` disables mutual TLS.
+    pub client_ca_path: Option<PathBuf>,
+}
+
+impl TlsConfig {
+    /// Builds the rustls `ServerConfig` the `mcp` and `routes` listeners bind with,
+    /// terminating TLS (and, if `client_ca_path` is set, verifying client certs) before
+    /// any request reaches `middleware`.
+    pub fn build_server_config(&self) -> Result<ServerConfig, TlsError> {
+        let cert_chain = load_certs(&self.cert_path)?;
+        let key = load_private_key(&self.key_path)?;
+
+        let builder = match &self.client_ca_path {
+            Some(ca_path) => {
+                let mut roots = RootCertStore::empty();
+                for cert in load_certs(ca_path)? {
+                    roots.add(cert)?;
+                }
+                let verifier = WebPkiClientVerifier::builder(Arc::new(roots)).build()?;
+                ServerConfig::builder().with_client_cert_verifier(verifier)
+            }
+            None => ServerConfig::builder().with_no_client_auth(),
+        };
+
+        Ok(builder.with_single_cert(cert_chain, key)?)
+    }
+}
+
+fn load_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>, TlsError> {
+    let bytes = fs::read(path).map_err(|source| TlsError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    certs(&mut Cursor::new(bytes))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|source| TlsError::Read {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+fn load_private_key(path: &Path) -> Result<PrivateKeyDer<'static>, TlsError> {
+    let bytes = fs::read(path).map_err(|source| TlsError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    private_key(&mut Cursor::new(bytes))
+        .map_err(|source| TlsError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?
+        .ok_or_else(|| TlsError::NoPrivateKey(path.to_path_buf()))
+}
+
+/// Generates a self-signed certificate/key pair for local development and writes them to
+/// `cert_path`/`key_path`. Not for production use — point [`TlsConfig`] at real certs there.
+pub fn generate_self_signed_dev_cert(cert_path: &Path, key_path: &Path) -> Result<(), TlsError> {
+    let cert = rcgen::generate_simple_self_signed(["localhost".to_string()])?;
+
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|source| TlsError::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+    };
+
+    write(cert_path, cert.cert.pem().as_str())?;
+    write(key_path, cert.key_pair.serialize_pem().as_str())?;
+
+    Ok(())
+}
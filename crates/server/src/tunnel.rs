@@ -0,0 +1,226 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use executors::approvals::{ApprovalRequest, ApprovalResponse, ExecutorApprovalService};
+use tokio::sync::{Mutex, broadcast, mpsc};
+use uuid::Uuid;
+
+const OUTPUT_CHANNEL_CAPACITY: usize = 1024;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TunnelError {
+    #[error("no live execution found for execution_process_id {0}")]
+    NotFound(Uuid),
+    #[error("approval lease for execution_process_id {0} is already held")]
+    LeaseHeld(Uuid),
+}
+
+/// A line of agent stdout/stderr, tagged by stream so viewers can render them distinctly.
+#[derive(Debug, Clone)]
+pub enum OutputLine {
+    Stdout(String),
+    Stderr(String),
+}
+
+/// A single in-flight execution that a remote client can attach to. Multiple viewers may
+/// subscribe to `output` read-only; only the holder of the approval lease may drain
+/// `approval_requests` and answer prompts through `ExecutorApprovalService`.
+struct Tunnel {
+    output: broadcast::Sender<OutputLine>,
+    approval_tx: mpsc::UnboundedSender<ApprovalRequest>,
+    approval_requests: Arc<Mutex<mpsc::UnboundedReceiver<ApprovalRequest>>>,
+    lease_held: Arc<RwLock<bool>>,
+}
+
+/// Read-only view onto a tunnel's output stream.
+pub struct TunnelViewer {
+    pub output: broadcast::Receiver<OutputLine>,
+}
+
+/// Exclusive right to drain and answer approval prompts for one execution. Dropping this
+/// releases the lease so another viewer can pick it up.
+pub struct ApprovalLease {
+    execution_process_id: Uuid,
+    lease_held: Arc<RwLock<bool>>,
+    requests: Arc<Mutex<mpsc::UnboundedReceiver<ApprovalRequest>>>,
+    approvals: Arc<dyn ExecutorApprovalService>,
+}
+
+impl ApprovalLease {
+    pub async fn next_request(&self) -> Option<ApprovalRequest> {
+        self.requests.lock().await.recv().await
+    }
+
+    pub async fn respond(
+        &self,
+        request_id: Uuid,
+        response: ApprovalResponse,
+    ) -> Result<(), TunnelError> {
+        self.approvals
+            .respond(request_id, response)
+            .await
+            .map_err(|_| TunnelError::NotFound(self.execution_process_id))
+    }
+}
+
+impl Drop for ApprovalLease {
+    fn drop(&mut self) {
+        *self.lease_held.write().unwrap() = false;
+    }
+}
+
+/// Holds every live attachable execution, keyed by `VkMcpContext.execution_process_id`.
+/// This is the relay an authenticated remote client connects through: one output
+/// channel per execution (fan-out to any number of viewers) and one approval channel
+/// (drained exclusively by a single viewer at a time).
+#[derive(Default)]
+pub struct TunnelRegistry {
+    tunnels: RwLock<HashMap<Uuid, Tunnel>>,
+}
+
+impl TunnelRegistry {
+    /// Registers a new attachable execution, returning the sender end the orchestrator
+    /// feeds output lines into as the attempt runs. Called before any remote client can
+    /// attach. The approval-request sender is kept internally (see
+    /// [`submit_approval_request`](Self::submit_approval_request)) rather than handed
+    /// back, since it's addressed by `execution_process_id` rather than held by a caller.
+    pub fn open(&self, execution_process_id: Uuid) -> broadcast::Sender<OutputLine> {
+        let (output_tx, _) = broadcast::channel(OUTPUT_CHANNEL_CAPACITY);
+        let (approval_tx, approval_rx) = mpsc::unbounded_channel();
+
+        let tunnel = Tunnel {
+            output: output_tx.clone(),
+            approval_tx,
+            approval_requests: Arc::new(Mutex::new(approval_rx)),
+            lease_held: Arc::new(RwLock::new(false)),
+        };
+
+        self.tunnels
+            .write()
+            .unwrap()
+            .insert(execution_process_id, tunnel);
+
+        output_tx
+    }
+
+    /// Removes an execution once it finishes, disconnecting any remaining viewers.
+    pub fn close(&self, execution_process_id: Uuid) {
+        self.tunnels.write().unwrap().remove(&execution_process_id);
+    }
+
+    /// Forwards an `ApprovalRequest` raised by the running execution into its tunnel, so
+    /// whoever holds the approval lease can drain it via [`ApprovalLease::next_request`].
+    /// Called by the MCP handler the executing agent calls back into.
+    pub fn submit_approval_request(
+        &self,
+        execution_process_id: Uuid,
+        request: ApprovalRequest,
+    ) -> Result<(), TunnelError> {
+        let tunnels = self.tunnels.read().unwrap();
+        let tunnel = tunnels
+            .get(&execution_process_id)
+            .ok_or(TunnelError::NotFound(execution_process_id))?;
+
+        // The receiver lives behind the same `Tunnel` entry we just looked up, and the read
+        // lock held here keeps `close` from removing that entry, so `send` does not fail in
+        // practice; any error is still reported as the same `NotFound` a missing entry gets.
+        tunnel
+            .approval_tx
+            .send(request)
+            .map_err(|_| TunnelError::NotFound(execution_process_id))
+    }
+
+    /// Attaches read-only to a live execution's output.
+    pub fn watch(&self, execution_process_id: Uuid) -> Result<TunnelViewer, TunnelError> {
+        let tunnels = self.tunnels.read().unwrap();
+        let tunnel = tunnels
+            .get(&execution_process_id)
+            .ok_or(TunnelError::NotFound(execution_process_id))?;
+
+        Ok(TunnelViewer {
+            output: tunnel.output.subscribe(),
+        })
+    }
+
+    /// Acquires the exclusive approval lease for an execution, if nobody else holds it.
+    pub fn acquire_approval_lease(
+        &self,
+        execution_process_id: Uuid,
+        approvals: Arc<dyn ExecutorApprovalService>,
+    ) -> Result<ApprovalLease, TunnelError> {
+        let tunnels = self.tunnels.read().unwrap();
+        let tunnel = tunnels
+            .get(&execution_process_id)
+            .ok_or(TunnelError::NotFound(execution_process_id))?;
+
+        let mut held = tunnel.lease_held.write().unwrap();
+        if *held {
+            return Err(TunnelError::LeaseHeld(execution_process_id));
+        }
+        *held = true;
+        drop(held);
+
+        Ok(ApprovalLease {
+            execution_process_id,
+            lease_held: tunnel.lease_held.clone(),
+            requests: tunnel.approval_requests.clone(),
+            approvals,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `acquire_approval_lease` and `submit_approval_request` need a live
+    // `Arc<dyn ExecutorApprovalService>` / `ApprovalRequest`, which this crate has no way
+    // to construct outside of a real deployment; the fan-out/not-found behavior below
+    // covers everything reachable without one.
+
+    #[test]
+    fn watch_unknown_execution_is_not_found() {
+        let registry = TunnelRegistry::default();
+        assert!(matches!(
+            registry.watch(Uuid::new_v4()),
+            Err(TunnelError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn output_fans_out_to_every_viewer() {
+        let registry = TunnelRegistry::default();
+        let execution_process_id = Uuid::new_v4();
+        let output_tx = registry.open(execution_process_id);
+
+        let mut first = registry.watch(execution_process_id).unwrap();
+        let mut second = registry.watch(execution_process_id).unwrap();
+
+        output_tx
+            .send(OutputLine::Stdout("hello".to_string()))
+            .unwrap();
+
+        for viewer in [&mut first, &mut second] {
+            match viewer.output.recv().await.unwrap() {
+                OutputLine::Stdout(line) => assert_eq!(line, "hello"),
+                OutputLine::Stderr(_) => panic!("expected stdout"),
+            }
+        }
+    }
+
+    #[test]
+    fn close_removes_the_tunnel() {
+        let registry = TunnelRegistry::default();
+        let execution_process_id = Uuid::new_v4();
+        registry.open(execution_process_id);
+        assert!(registry.watch(execution_process_id).is_ok());
+
+        registry.close(execution_process_id);
+        assert!(matches!(
+            registry.watch(execution_process_id),
+            Err(TunnelError::NotFound(_))
+        ));
+    }
+}